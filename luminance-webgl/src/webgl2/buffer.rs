@@ -3,8 +3,6 @@
 use std::cell::RefCell;
 use std::cmp::Ordering;
 use std::mem;
-use std::os::raw::c_void;
-use std::ptr;
 use std::rc::Rc;
 use std::slice;
 use web_sys::{WebGl2RenderingContext, WebGlBuffer};
@@ -14,7 +12,22 @@ use crate::webgl2::WebGL2;
 use luminance::backend::buffer::{
   Buffer as BufferBackend, BufferBase, BufferSlice as BufferSliceBackend,
 };
-use luminance::buffer::BufferError;
+use luminance::buffer::{BufferError, BufferUsage};
+
+/// Translate a [`BufferUsage`] into the matching WebGL2 usage constant.
+fn webgl_usage(usage: BufferUsage) -> u32 {
+  match usage {
+    BufferUsage::StreamDraw => WebGl2RenderingContext::STREAM_DRAW,
+    BufferUsage::StaticDraw => WebGl2RenderingContext::STATIC_DRAW,
+    BufferUsage::DynamicDraw => WebGl2RenderingContext::DYNAMIC_DRAW,
+    BufferUsage::StreamRead => WebGl2RenderingContext::STREAM_READ,
+    BufferUsage::StaticRead => WebGl2RenderingContext::STATIC_READ,
+    BufferUsage::DynamicRead => WebGl2RenderingContext::DYNAMIC_READ,
+    BufferUsage::StreamCopy => WebGl2RenderingContext::STREAM_COPY,
+    BufferUsage::StaticCopy => WebGl2RenderingContext::STATIC_COPY,
+    BufferUsage::DynamicCopy => WebGl2RenderingContext::DYNAMIC_COPY,
+  }
+}
 
 /// WebGL buffer.
 #[derive(Clone)]
@@ -30,7 +43,11 @@ unsafe impl BufferBase for WebGL2 {
 }
 
 unsafe impl<T> BufferBackend<T> for WebGL2 {
-  unsafe fn new_buffer(&mut self, len: usize) -> Result<Self::BufferRepr, BufferError> {
+  unsafe fn new_buffer(
+    &mut self,
+    len: usize,
+    usage: BufferUsage,
+  ) -> Result<Self::BufferRepr, BufferError> {
     let bytes = mem::size_of::<T>() * len;
     let mut state = self.state.borrow_mut();
 
@@ -44,7 +61,7 @@ unsafe impl<T> BufferBackend<T> for WebGL2 {
     state.ctx.buffer_data_with_i32(
       WebGl2RenderingContext::ARRAY_BUFFER,
       bytes as i32,
-      WebGl2RenderingContext::STREAM_DRAW,
+      webgl_usage(usage),
     );
 
     Ok(Buffer {
@@ -66,7 +83,11 @@ unsafe impl<T> BufferBackend<T> for WebGL2 {
     buffer.len
   }
 
-  unsafe fn from_slice<S>(&mut self, slice: S) -> Result<Self::BufferRepr, BufferError>
+  unsafe fn from_slice<S>(
+    &mut self,
+    slice: S,
+    usage: BufferUsage,
+  ) -> Result<Self::BufferRepr, BufferError>
   where
     S: AsRef<[T]>,
   {
@@ -84,7 +105,7 @@ unsafe impl<T> BufferBackend<T> for WebGL2 {
     state.ctx.buffer_data_with_u8_array(
       WebGl2RenderingContext::ARRAY_BUFFER,
       data,
-      WebGl2RenderingContext::STREAM_DRAW,
+      webgl_usage(usage),
     );
 
     Ok(Buffer {
@@ -111,169 +132,169 @@ unsafe impl<T> BufferBackend<T> for WebGL2 {
     if i >= buffer.len {
       None
     } else {
-      buffer
-        .state
-        .borrow_mut()
-        .bind_array_buffer(Some(&buffer.buf), Bind::Cached);
-      let ptr = gl::MapBuffer(gl::ARRAY_BUFFER, gl::READ_ONLY) as *const T;
-      let x = *ptr.add(i);
-      let _ = gl::UnmapBuffer(gl::ARRAY_BUFFER);
-
-      Some(x)
+      let mut state = buffer.state.borrow_mut();
+      state.bind_array_buffer(Some(&buffer.buf), Bind::Cached);
+
+      let mut x = mem::MaybeUninit::<T>::uninit();
+      let dst = slice::from_raw_parts_mut(x.as_mut_ptr() as *mut u8, mem::size_of::<T>());
+      state.ctx.get_buffer_sub_data_with_i32_and_u8_array_and_dst_offset(
+        WebGl2RenderingContext::ARRAY_BUFFER,
+        (i * mem::size_of::<T>()) as i32,
+        dst,
+        0,
+      );
+
+      Some(x.assume_init())
     }
   }
 
-  //unsafe fn whole(buffer: &Self::BufferRepr) -> Vec<T>
-  //where
-  //  T: Copy,
-  //{
-  //  buffer
-  //    .state
-  //    .borrow_mut()
-  //    .bind_array_buffer(buffer.handle, Bind::Cached);
-  //  let ptr = gl::MapBuffer(gl::ARRAY_BUFFER, gl::READ_ONLY) as *mut T;
-  //  let values = Vec::from_raw_parts(ptr, buffer.len, buffer.len);
-  //  let _ = gl::UnmapBuffer(gl::ARRAY_BUFFER);
-
-  //  values
-  //}
+  unsafe fn set(buffer: &mut Self::BufferRepr, i: usize, x: T) -> Result<(), BufferError>
+  where
+    T: Copy,
+  {
+    if i >= buffer.len {
+      Err(BufferError::Overflow {
+        index: i,
+        buffer_len: buffer.len,
+      })
+    } else {
+      let mut state = buffer.state.borrow_mut();
+      state.bind_array_buffer(Some(&buffer.buf), Bind::Cached);
 
-  //unsafe fn set(buffer: &mut Self::BufferRepr, i: usize, x: T) -> Result<(), BufferError>
-  //where
-  //  T: Copy,
-  //{
-  //  if i >= buffer.len {
-  //    Err(BufferError::Overflow {
-  //      index: i,
-  //      buffer_len: buffer.len,
-  //    })
-  //  } else {
-  //    buffer
-  //      .state
-  //      .borrow_mut()
-  //      .bind_array_buffer(buffer.handle, Bind::Cached);
-  //    let ptr = gl::MapBuffer(gl::ARRAY_BUFFER, gl::WRITE_ONLY) as *mut T;
-  //    *ptr.add(i) = x;
-  //    let _ = gl::UnmapBuffer(gl::ARRAY_BUFFER);
-
-  //    Ok(())
-  //  }
-  //}
+      let src = slice::from_raw_parts(&x as *const T as *const u8, mem::size_of::<T>());
+      state.ctx.buffer_sub_data_with_i32_and_u8_array(
+        WebGl2RenderingContext::ARRAY_BUFFER,
+        (i * mem::size_of::<T>()) as i32,
+        src,
+      );
 
-  //unsafe fn write_whole(buffer: &mut Self::BufferRepr, values: &[T]) -> Result<(), BufferError> {
-  //  let len = values.len();
-  //  let in_bytes = len * mem::size_of::<T>();
-
-  //  // generate warning and recompute the proper number of bytes to copy
-  //  let real_bytes = match in_bytes.cmp(&buffer.bytes) {
-  //    Ordering::Less => {
-  //      return Err(BufferError::TooFewValues {
-  //        provided_len: len,
-  //        buffer_len: buffer.len,
-  //      })
-  //    }
-
-  //    Ordering::Greater => {
-  //      return Err(BufferError::TooManyValues {
-  //        provided_len: len,
-  //        buffer_len: buffer.len,
-  //      })
-  //    }
-
-  //    _ => in_bytes,
-  //  };
-
-  //  buffer
-  //    .state
-  //    .borrow_mut()
-  //    .bind_array_buffer(buffer.handle, Bind::Cached);
-  //  let ptr = gl::MapBuffer(gl::ARRAY_BUFFER, gl::WRITE_ONLY);
-  //  ptr::copy_nonoverlapping(values.as_ptr() as *const c_void, ptr, real_bytes);
-  //  let _ = gl::UnmapBuffer(gl::ARRAY_BUFFER);
-
-  //  Ok(())
-  //}
+      Ok(())
+    }
+  }
 
-  //unsafe fn clear(buffer: &mut Self::BufferRepr, x: T) -> Result<(), BufferError>
-  //where
-  //  T: Copy,
-  //{
-  //  Self::write_whole(buffer, &vec![x; buffer.len])
-  //}
-  //}
+  unsafe fn write_whole(buffer: &mut Self::BufferRepr, values: &[T]) -> Result<(), BufferError> {
+    let len = values.len();
+    let in_bytes = len * mem::size_of::<T>();
+
+    // ensure we are writing exactly the right amount of bytes
+    let real_bytes = match in_bytes.cmp(&buffer.bytes) {
+      Ordering::Less => {
+        return Err(BufferError::TooFewValues {
+          provided_len: len,
+          buffer_len: buffer.len,
+        })
+      }
+
+      Ordering::Greater => {
+        return Err(BufferError::TooManyValues {
+          provided_len: len,
+          buffer_len: buffer.len,
+        })
+      }
+
+      _ => in_bytes,
+    };
 
-  //pub struct BufferSlice<T> {
-  //buffer: RawBuffer,
-  //ptr: *const T,
-  //}
+    let mut state = buffer.state.borrow_mut();
+    state.bind_array_buffer(Some(&buffer.buf), Bind::Cached);
 
-  //pub struct BufferSliceMut<T> {
-  //buffer: RawBuffer,
-  //ptr: *mut T,
-  //}
+    let src = slice::from_raw_parts(values.as_ptr() as *const u8, real_bytes);
+    state.ctx.buffer_sub_data_with_i32_and_u8_array(
+      WebGl2RenderingContext::ARRAY_BUFFER,
+      0,
+      src,
+    );
+
+    Ok(())
+  }
+
+  unsafe fn clear(buffer: &mut Self::BufferRepr, x: T) -> Result<(), BufferError>
+  where
+    T: Copy,
+  {
+    Self::write_whole(buffer, &vec![x; buffer.len])
+  }
+}
 
-  //unsafe impl<T> BufferSliceBackend<T> for WebGL2 {
-  //type SliceRepr = BufferSlice<T>;
+/// WebGL2 has no `glMapBuffer`, so a slice is emulated with a host-side staging `Vec` that is
+/// filled on creation and, for the mutable variant, written back on destruction. The staging
+/// storage is owned by the slice so that the `&[T]` handed out by `obtain_slice` stays valid for
+/// the slice’s lifetime.
+pub struct BufferSlice<T> {
+  buffer: Buffer,
+  staging: Vec<T>,
+}
 
-  //type SliceMutRepr = BufferSliceMut<T>;
+pub struct BufferSliceMut<T> {
+  buffer: Buffer,
+  staging: Vec<T>,
+}
 
-  //unsafe fn slice_buffer(buffer: &Self::BufferRepr) -> Result<Self::SliceRepr, BufferError> {
-  //  buffer
-  //    .state
-  //    .borrow_mut()
-  //    .bind_array_buffer(buffer.handle, Bind::Cached);
+/// Read the whole content of `buffer` into a freshly allocated staging `Vec`.
+unsafe fn read_back<T>(buffer: &Buffer) -> Vec<T> {
+  let mut staging: Vec<T> = Vec::with_capacity(buffer.len);
+  staging.set_len(buffer.len);
 
-  //  let ptr = gl::MapBuffer(gl::ARRAY_BUFFER, gl::READ_ONLY) as *const T;
-  //  let buffer = buffer.clone();
+  let mut state = buffer.state.borrow_mut();
+  state.bind_array_buffer(Some(&buffer.buf), Bind::Cached);
 
-  //  if ptr.is_null() {
-  //    Err(BufferError::MapFailed)
-  //  } else {
-  //    Ok(BufferSlice { buffer, ptr })
-  //  }
-  //}
+  let dst = slice::from_raw_parts_mut(staging.as_mut_ptr() as *mut u8, buffer.bytes);
+  state.ctx.get_buffer_sub_data_with_i32_and_u8_array_and_dst_offset(
+    WebGl2RenderingContext::ARRAY_BUFFER,
+    0,
+    dst,
+    0,
+  );
 
-  //unsafe fn slice_buffer_mut(
-  //  buffer: &mut Self::BufferRepr,
-  //) -> Result<Self::SliceMutRepr, BufferError> {
-  //  buffer
-  //    .state
-  //    .borrow_mut()
-  //    .bind_array_buffer(buffer.handle, Bind::Cached);
-
-  //  let ptr = gl::MapBuffer(gl::ARRAY_BUFFER, gl::READ_WRITE) as *mut T;
-  //  let buffer = buffer.clone();
-
-  //  if ptr.is_null() {
-  //    Err(BufferError::MapFailed)
-  //  } else {
-  //    Ok(BufferSliceMut { buffer, ptr })
-  //  }
-  //}
+  staging
+}
 
-  //unsafe fn destroy_buffer_slice(slice: &mut Self::SliceRepr) {
-  //  slice
-  //    .buffer
-  //    .state
-  //    .borrow_mut()
-  //    .bind_array_buffer(slice.buffer.handle, Bind::Cached);
-  //  gl::UnmapBuffer(gl::ARRAY_BUFFER);
-  //}
+unsafe impl<T> BufferSliceBackend<T> for WebGL2 {
+  type SliceRepr = BufferSlice<T>;
 
-  //unsafe fn destroy_buffer_slice_mut(slice: &mut Self::SliceMutRepr) {
-  //  slice
-  //    .buffer
-  //    .state
-  //    .borrow_mut()
-  //    .bind_array_buffer(slice.buffer.handle, Bind::Cached);
-  //  gl::UnmapBuffer(gl::ARRAY_BUFFER);
-  //}
+  type SliceMutRepr = BufferSliceMut<T>;
 
-  //unsafe fn obtain_slice(slice: &Self::SliceRepr) -> Result<&[T], BufferError> {
-  //  Ok(slice::from_raw_parts(slice.ptr, slice.buffer.len))
-  //}
+  unsafe fn slice_buffer(buffer: &Self::BufferRepr) -> Result<Self::SliceRepr, BufferError> {
+    let staging = read_back(buffer);
 
-  //unsafe fn obtain_slice_mut(slice: &mut Self::SliceMutRepr) -> Result<&mut [T], BufferError> {
-  //  Ok(slice::from_raw_parts_mut(slice.ptr, slice.buffer.len))
-  //}
+    Ok(BufferSlice {
+      buffer: buffer.clone(),
+      staging,
+    })
+  }
+
+  unsafe fn slice_buffer_mut(
+    buffer: &mut Self::BufferRepr,
+  ) -> Result<Self::SliceMutRepr, BufferError> {
+    let staging = read_back(buffer);
+
+    Ok(BufferSliceMut {
+      buffer: buffer.clone(),
+      staging,
+    })
+  }
+
+  unsafe fn destroy_buffer_slice(_slice: &mut Self::SliceRepr) {
+    // read-only slice: nothing to flush back to GPU memory
+  }
+
+  unsafe fn destroy_buffer_slice_mut(slice: &mut Self::SliceMutRepr) {
+    let mut state = slice.buffer.state.borrow_mut();
+    state.bind_array_buffer(Some(&slice.buffer.buf), Bind::Cached);
+
+    let src = slice::from_raw_parts(slice.staging.as_ptr() as *const u8, slice.buffer.bytes);
+    state.ctx.buffer_sub_data_with_i32_and_u8_array(
+      WebGl2RenderingContext::ARRAY_BUFFER,
+      0,
+      src,
+    );
+  }
+
+  unsafe fn obtain_slice(slice: &Self::SliceRepr) -> Result<&[T], BufferError> {
+    Ok(&slice.staging)
+  }
+
+  unsafe fn obtain_slice_mut(slice: &mut Self::SliceMutRepr) -> Result<&mut [T], BufferError> {
+    Ok(&mut slice.staging)
+  }
 }
@@ -61,6 +61,21 @@ pub unsafe trait TessBuilder {
     tess_builder: &mut Self::TessBuilderRepr,
     index: Option<u32>,
   ) -> Result<(), TessError>;
+
+  /// Concatenate several strips into a single indexed tessellation.
+  ///
+  /// The sub-strips are flattened into one index buffer, inserting the builder’s configured
+  /// primitive-restart index between each of them — the maximum value of `I` when none was set,
+  /// matching GL’s default primitive-restart-fixed-index behaviour — and enabling primitive
+  /// restart. The builder’s [`Mode`] is used as-is and must be a strip or fan mode, otherwise a
+  /// [`TessError`] is returned. This lets many disjoint strips be drawn in a single call.
+  unsafe fn add_strip_group<I>(
+    &mut self,
+    tess_builder: &mut Self::TessBuilderRepr,
+    strips: &[&[I]],
+  ) -> Result<(), TessError>
+  where
+    I: Copy + TessIndex;
 }
 
 pub unsafe trait TessBuilderBuffer<T>: TessBuilder + Buffer<T>
@@ -0,0 +1,36 @@
+//! Usage hints for GPU buffers.
+
+/// Hint describing how a buffer’s data store will be accessed.
+///
+/// This mirrors the GL `usage` argument passed when the data store is (re)specified and lets the
+/// driver pick an optimal memory placement. `*Draw` means the application writes and the GPU reads,
+/// `*Read` means the GPU writes and the application reads, and `*Copy` means both are GPU-driven.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum BufferUsage {
+  /// Data specified once and used at most a few times.
+  StreamDraw,
+  /// Data specified once and used many times.
+  StaticDraw,
+  /// Data specified and used many times.
+  DynamicDraw,
+  /// Read-back analogue of `StreamDraw`.
+  StreamRead,
+  /// Read-back analogue of `StaticDraw`.
+  StaticRead,
+  /// Read-back analogue of `DynamicDraw`.
+  DynamicRead,
+  /// GPU-to-GPU analogue of `StreamDraw`.
+  StreamCopy,
+  /// GPU-to-GPU analogue of `StaticDraw`.
+  StaticCopy,
+  /// GPU-to-GPU analogue of `DynamicDraw`.
+  DynamicCopy,
+}
+
+impl Default for BufferUsage {
+  /// Tess-owned geometry buffers are uploaded once and drawn every frame, so `StaticDraw` is the
+  /// sensible default.
+  fn default() -> Self {
+    BufferUsage::StaticDraw
+  }
+}
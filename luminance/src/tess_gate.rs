@@ -4,9 +4,11 @@
 //!
 //! [`Tess`]: crate::tess::Tess
 
+use std::ops::{Range, RangeFrom, RangeFull, RangeTo};
+
 use crate::backend::tess_gate::TessGate as TessGateBackend;
 use crate::context::GraphicsContext;
-use crate::tess::TessView;
+use crate::tess::{Tess, TessView};
 
 /// Tessellation gates.
 pub struct TessGate<'a, C>
@@ -38,3 +40,76 @@ where
     }
   }
 }
+
+/// Build a [`TessView`] covering `start .. start + nb`, clamped to the tess’ vertex count.
+fn view_range<'a, B>(
+  tess: &'a Tess<B>,
+  start: usize,
+  nb: usize,
+  inst_nb: usize,
+) -> TessView<'a, B>
+where
+  B: TessGateBackend,
+{
+  let vert_nb = tess.tess_vertices_nb();
+  let start_index = start.min(vert_nb);
+  let vert_nb = nb.min(vert_nb - start_index);
+
+  TessView {
+    tess,
+    start_index,
+    vert_nb,
+    inst_nb,
+  }
+}
+
+impl<'a, B> From<(Range<usize>, &'a Tess<B>)> for TessView<'a, B>
+where
+  B: TessGateBackend,
+{
+  fn from((range, tess): (Range<usize>, &'a Tess<B>)) -> Self {
+    view_range(tess, range.start, range.end.saturating_sub(range.start), 1)
+  }
+}
+
+impl<'a, B> From<(RangeFrom<usize>, &'a Tess<B>)> for TessView<'a, B>
+where
+  B: TessGateBackend,
+{
+  fn from((range, tess): (RangeFrom<usize>, &'a Tess<B>)) -> Self {
+    view_range(tess, range.start, usize::max_value(), 1)
+  }
+}
+
+impl<'a, B> From<(RangeTo<usize>, &'a Tess<B>)> for TessView<'a, B>
+where
+  B: TessGateBackend,
+{
+  fn from((range, tess): (RangeTo<usize>, &'a Tess<B>)) -> Self {
+    view_range(tess, 0, range.end, 1)
+  }
+}
+
+impl<'a, B> From<(RangeFull, &'a Tess<B>)> for TessView<'a, B>
+where
+  B: TessGateBackend,
+{
+  fn from((_, tess): (RangeFull, &'a Tess<B>)) -> Self {
+    view_range(tess, 0, usize::max_value(), 1)
+  }
+}
+
+/// Instanced variant: the trailing `usize` is the number of instances to draw.
+impl<'a, B> From<(Range<usize>, &'a Tess<B>, usize)> for TessView<'a, B>
+where
+  B: TessGateBackend,
+{
+  fn from((range, tess, inst_nb): (Range<usize>, &'a Tess<B>, usize)) -> Self {
+    view_range(
+      tess,
+      range.start,
+      range.end.saturating_sub(range.start),
+      inst_nb,
+    )
+  }
+}
@@ -17,7 +17,10 @@ pub enum Wrap {
   /// ```
   Repeat,
   /// Same as `Repeat` but it will alternatively repeat between *[0;1]* and *[1;0]*.
-  MirroredRepeat
+  MirroredRepeat,
+  /// If textures coordinates lay outside of *[0;1]*, the `Sampler`’s `border_color` is returned
+  /// instead of smearing the edge texel. Useful for shadow maps, decals and atlas padding.
+  ClampToBorder
 }
 
 /// Minification and magnification filter.
@@ -161,6 +164,12 @@ pub trait HasTexture {
   type ATex;
 
   /// Create a new texture.
+  ///
+  /// The `sampler` configures wrapping and filtering; backends translate `max_anisotropy` to
+  /// `TEXTURE_MAX_ANISOTROPY_EXT` (clamping to *1.0* when the extension is missing) and
+  /// `lod_bias` / `lod_min` / `lod_max` to `TEXTURE_LOD_BIAS` and `TEXTURE_MIN/MAX_LOD`. When any
+  /// wrap axis is `Wrap::ClampToBorder`, the matching `TEXTURE_WRAP_*` is set to `CLAMP_TO_BORDER`
+  /// and `border_color` is uploaded through `TEXTURE_BORDER_COLOR`.
   fn new<L, D, P>(size: D::Size, mipmaps: u32, sampler: &Sampler) -> Self::ATex
     where L: Layerable,
           D: Dimensionable,
@@ -171,6 +180,13 @@ pub trait HasTexture {
   fn clear<P>(tex: &Self::ATex, pixel: &P::Encoding) where P: Pixel;
   /// Upload texels to the texture’s memory.
   fn upload<P>(tex: &Self::ATex, texels: &Vec<P::Encoding>) where P: Pixel;
+  /// Read the texels of a given `mipmap` level back from GPU memory.
+  ///
+  /// The returned `Vec` holds `dim_capacity` elements laid out according to the texture’s `Dim`
+  /// and `P::Encoding`. On desktop GL this maps to `glGetTexImage`; the WebGL2 backend, which has
+  /// no `glGetTexImage`, emulates it by attaching the texture to a temporary framebuffer and
+  /// reading it back with `readPixels`.
+  fn download<P>(tex: &Self::ATex, mipmap: u32) -> Vec<P::Encoding> where P: Pixel;
 }
 
 /// Texture.
@@ -219,6 +235,10 @@ impl<C, L, D, P> Tex<C, L, D, P>
   pub fn upload(&self, texels: &Vec<P::Encoding>) {
     C::upload::<P>(&self.repr, texels)
   }
+
+  pub fn download(&self, mipmap: u32) -> Vec<P::Encoding> {
+    C::download::<P>(&self.repr, mipmap)
+  }
 }
 
 /// A `Sampler` object gives hint on how a `Tex` should be sampled.
@@ -234,6 +254,19 @@ pub struct Sampler {
   pub minification: Filter,
   /// Magnification filter.
   pub magnification: Filter,
+  /// Maximum anisotropy to use while sampling. *1.0* disables anisotropic filtering; higher values
+  /// ask the backend for up to that many samples along the anisotropy axis. Backends lacking the
+  /// anisotropy extension clamp this to *1.0* silently.
+  pub max_anisotropy: f32,
+  /// Bias added to the computed level-of-detail before sampling.
+  pub lod_bias: f32,
+  /// Lower bound clamp applied to the level-of-detail.
+  pub lod_min: f32,
+  /// Upper bound clamp applied to the level-of-detail.
+  pub lod_max: f32,
+  /// Border color sampled when any wrap axis selects `Wrap::ClampToBorder`. Defaults to
+  /// transparent black.
+  pub border_color: [f32; 4],
   /// For depth textures, should we perform depth comparison and if so, how?
   pub depth_comparison: Option<DepthComparison>
 }
@@ -247,6 +280,11 @@ pub struct Sampler {
 ///   wrap_t: Wrap::ClampToEdge,
 ///   minification: Filter::Linear,
 ///   magnification: Filter::Linear,
+///   max_anisotropy: 1.,
+///   lod_bias: 0.,
+///   lod_min: -1000.,
+///   lod_max: 1000.,
+///   border_color: [0., 0., 0., 0.],
 ///   depth_comparison: None
 /// }
 /// ```
@@ -258,7 +296,34 @@ impl Default for Sampler {
       wrap_t: Wrap::ClampToEdge,
       minification: Filter::Linear,
       magnification: Filter::Linear,
+      max_anisotropy: 1.,
+      lod_bias: 0.,
+      lod_min: -1000.,
+      lod_max: 1000.,
+      border_color: [0., 0., 0., 0.],
       depth_comparison: None
     }
   }
 }
+
+impl Sampler {
+  /// Clamp `max_anisotropy` to what the backend actually supports before handing it to
+  /// `TEXTURE_MAX_ANISOTROPY_EXT`.
+  ///
+  /// `max_supported` is the largest anisotropy the backend advertises; it is *1.0* when the
+  /// anisotropy extension is missing, in which case the result collapses to *1.0* and the knob is
+  /// silently disabled, as documented.
+  pub fn clamped_anisotropy(&self, max_supported: f32) -> f32 {
+    self.max_anisotropy.max(1.).min(max_supported)
+  }
+
+  /// Does any wrap axis request `Wrap::ClampToBorder`?
+  ///
+  /// Backends use this to decide whether to set `TEXTURE_WRAP_*` to `CLAMP_TO_BORDER` and upload
+  /// `border_color` through `TEXTURE_BORDER_COLOR`.
+  pub fn uses_clamp_to_border(&self) -> bool {
+    [self.wrap_r, self.wrap_s, self.wrap_t]
+      .iter()
+      .any(|wrap| matches!(wrap, Wrap::ClampToBorder))
+  }
+}